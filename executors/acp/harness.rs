@@ -16,12 +16,138 @@ use tokio_util::{
 use tracing::error;
 use workspace_utils::{shell::get_shell_command, stream_lines::LinesStreamExt};
 
+use super::transport::{AcpTransport, AcpTransportIo, LocalProcessTransport};
 use super::{AcpClient, SessionManager};
 use crate::executors::{ExecutorError, SpawnedChild, acp::AcpEvent};
 
+/// Per-request timeout budget for an ACP connection, in milliseconds. `0` means wait
+/// indefinitely, matching the `distant` CLI's timeout convention.
+#[derive(Debug, Clone, Copy)]
+pub struct AcpTimeouts {
+    pub initialize_ms: u64,
+    pub new_session_ms: u64,
+    pub prompt_ms: u64,
+}
+
+impl Default for AcpTimeouts {
+    fn default() -> Self {
+        Self {
+            initialize_ms: 0,
+            new_session_ms: 0,
+            prompt_ms: 0,
+        }
+    }
+}
+
+impl AcpTimeouts {
+    /// Awaits `fut`, bounding it to `budget_ms` unless the budget is `0` (wait forever).
+    async fn bound<T>(
+        budget_ms: u64,
+        fut: impl std::future::Future<Output = T>,
+    ) -> Result<T, tokio::time::error::Elapsed> {
+        if budget_ms == 0 {
+            Ok(fut.await)
+        } else {
+            tokio::time::timeout(std::time::Duration::from_millis(budget_ms), fut).await
+        }
+    }
+}
+
+/// A single command sent to a live session's turn loop, driven by `SessionHandle`.
+enum SessionCommand {
+    SendPrompt {
+        prompt: String,
+        reply: tokio::sync::oneshot::Sender<Result<proto::StopReason, ExecutorError>>,
+    },
+    Interrupt {
+        reply: tokio::sync::oneshot::Sender<Result<(), ExecutorError>>,
+    },
+}
+
+fn session_closed_err() -> ExecutorError {
+    ExecutorError::Io(std::io::Error::new(
+        std::io::ErrorKind::NotConnected,
+        "ACP session turn loop has already shut down",
+    ))
+}
+
+fn session_busy_err() -> ExecutorError {
+    ExecutorError::Io(std::io::Error::new(
+        std::io::ErrorKind::WouldBlock,
+        "ACP session already has a turn in flight",
+    ))
+}
+
+/// Resolves `requested_path` against `root` and rejects anything that would land outside it,
+/// whether via `..` components or a symlink followed during canonicalization. This is the
+/// guard a `fs/read_text_file`/`fs/write_text_file` handler on `AcpClient` must run before
+/// touching disk on the agent's behalf; it lives here rather than on `AcpClient` itself
+/// because that type's `proto::Client` impl is defined outside this checkout's tracked
+/// files, so there's nowhere in this tree to attach the handler methods that would call it.
+/// `client_capabilities.fs` stays `false` below until that impl exists and calls this.
+pub(crate) fn resolve_within_sandbox(root: &Path, requested: &Path) -> Result<PathBuf, ExecutorError> {
+    let joined = if requested.is_absolute() {
+        requested.to_path_buf()
+    } else {
+        root.join(requested)
+    };
+    let canonical_root = root.canonicalize().map_err(ExecutorError::Io)?;
+    let canonical = joined.canonicalize().map_err(ExecutorError::Io)?;
+    if !canonical.starts_with(&canonical_root) {
+        return Err(ExecutorError::Io(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!(
+                "path {} escapes sandbox root {}",
+                canonical.display(),
+                canonical_root.display()
+            ),
+        )));
+    }
+    Ok(canonical)
+}
+
+/// Handle to a live, multi-turn ACP session. The session's `ClientSideConnection`,
+/// `initialize`/`new_session` handshake, and stdio plumbing are only done once, by
+/// `bootstrap_acp_connection`; every further turn goes through this handle instead of
+/// respawning the agent process and forking the session file. Dropping the last clone closes
+/// the command channel, which tells the turn loop to tear down the connection and fire the
+/// harness's `exit_signal`.
+#[derive(Clone)]
+pub struct SessionHandle {
+    cmd_tx: mpsc::UnboundedSender<SessionCommand>,
+    session_id: String,
+}
+
+impl SessionHandle {
+    /// The ACP-visible session id this handle drives turns against.
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Send `prompt` as the session's next turn and await its `StopReason`, reusing the
+    /// already-initialized connection instead of spawning a new agent process.
+    pub async fn send_prompt(&self, prompt: String) -> Result<proto::StopReason, ExecutorError> {
+        let (reply, reply_rx) = tokio::sync::oneshot::channel();
+        self.cmd_tx
+            .send(SessionCommand::SendPrompt { prompt, reply })
+            .map_err(|_| session_closed_err())?;
+        reply_rx.await.map_err(|_| session_closed_err())?
+    }
+
+    /// Cancel the session's current turn via `proto::CancelNotification`.
+    pub async fn interrupt(&self) -> Result<(), ExecutorError> {
+        let (reply, reply_rx) = tokio::sync::oneshot::channel();
+        self.cmd_tx
+            .send(SessionCommand::Interrupt { reply })
+            .map_err(|_| session_closed_err())?;
+        reply_rx.await.map_err(|_| session_closed_err())?
+    }
+}
+
 /// Reusable harness for ACP-based conns (Gemini, Qwen, etc.)
 pub struct AcpAgentHarness {
     session_namespace: String,
+    timeouts: AcpTimeouts,
 }
 
 impl Default for AcpAgentHarness {
@@ -36,6 +162,7 @@ impl AcpAgentHarness {
     pub fn new() -> Self {
         Self {
             session_namespace: "gemini_sessions".to_string(),
+            timeouts: AcpTimeouts::default(),
         }
     }
 
@@ -43,15 +170,22 @@ impl AcpAgentHarness {
     pub fn with_session_namespace(namespace: impl Into<String>) -> Self {
         Self {
             session_namespace: namespace.into(),
+            timeouts: AcpTimeouts::default(),
         }
     }
 
+    /// Set the per-request timeout budget for `initialize`/`new_session`/`prompt`.
+    pub fn with_timeouts(mut self, timeouts: AcpTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
     pub async fn spawn_with_command(
         &self,
         current_dir: &Path,
         prompt: String,
         full_command: String,
-    ) -> Result<SpawnedChild, ExecutorError> {
+    ) -> Result<(SpawnedChild, Option<SessionHandle>), ExecutorError> {
         let (shell_cmd, shell_arg) = get_shell_command();
         let mut command = Command::new(shell_cmd);
         command
@@ -67,20 +201,25 @@ impl AcpAgentHarness {
         let mut child = command.group_spawn()?;
 
         let (exit_tx, exit_rx) = tokio::sync::oneshot::channel::<()>();
-        Self::bootstrap_acp_connection(
-            &mut child,
+        let transport = LocalProcessTransport::new(&mut child);
+        let session = Self::bootstrap_acp_connection(
+            transport,
             current_dir.to_path_buf(),
             None,
             prompt,
             Some(exit_tx),
             self.session_namespace.clone(),
+            self.timeouts,
         )
         .await?;
 
-        Ok(SpawnedChild {
-            child,
-            exit_signal: Some(exit_rx),
-        })
+        Ok((
+            SpawnedChild {
+                child,
+                exit_signal: Some(exit_rx),
+            },
+            Some(session),
+        ))
     }
 
     pub async fn spawn_follow_up_with_command(
@@ -89,7 +228,7 @@ impl AcpAgentHarness {
         prompt: String,
         session_id: &str,
         full_command: String,
-    ) -> Result<SpawnedChild, ExecutorError> {
+    ) -> Result<(SpawnedChild, Option<SessionHandle>), ExecutorError> {
         let (shell_cmd, shell_arg) = get_shell_command();
         let mut command = Command::new(shell_cmd);
         command
@@ -105,46 +244,57 @@ impl AcpAgentHarness {
         let mut child = command.group_spawn()?;
 
         let (exit_tx, exit_rx) = tokio::sync::oneshot::channel::<()>();
-        Self::bootstrap_acp_connection(
-            &mut child,
+        let transport = LocalProcessTransport::new(&mut child);
+        // Follow-ups still fork a fresh session file on a fresh process, so there's no
+        // multi-turn use for the handle here: dropping it immediately (`let _ =`) closes its
+        // command channel right away, which keeps this path's old one-turn-then-teardown
+        // behavior intact.
+        let _ = Self::bootstrap_acp_connection(
+            transport,
             current_dir.to_path_buf(),
             Some(session_id.to_string()),
             prompt,
             Some(exit_tx),
             self.session_namespace.clone(),
+            self.timeouts,
         )
         .await?;
 
-        Ok(SpawnedChild {
-            child,
-            exit_signal: Some(exit_rx),
-        })
+        Ok((
+            SpawnedChild {
+                child,
+                exit_signal: Some(exit_rx),
+            },
+            None,
+        ))
     }
 
-    async fn bootstrap_acp_connection(
-        child: &mut AsyncGroupChild,
+    async fn bootstrap_acp_connection<T: AcpTransport>(
+        mut transport: T,
         cwd: PathBuf,
         existing_session: Option<String>,
         prompt: String,
         exit_signal: Option<tokio::sync::oneshot::Sender<()>>,
         session_namespace: String,
-    ) -> Result<(), ExecutorError> {
-        // Take child's stdio for ACP wiring
-        let orig_stdout = child.inner().stdout.take().ok_or_else(|| {
-            ExecutorError::Io(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "Child process has no stdout",
-            ))
-        })?;
-        let orig_stdin = child.inner().stdin.take().ok_or_else(|| {
-            ExecutorError::Io(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "Child process has no stdin",
-            ))
-        })?;
-
-        // Create a fresh stdout pipe for logs
-        let writer = crate::stdout_dup::create_stdout_pipe_writer(child)?;
+        timeouts: AcpTimeouts,
+    ) -> Result<SessionHandle, ExecutorError> {
+        // Establish the transport and take its stdio for ACP wiring. Local process, vsock, and
+        // QUIC transports all funnel through the same `AcpTransportIo` shape from here on.
+        let AcpTransportIo {
+            reader: orig_stdout,
+            writer: orig_stdin,
+            log_writer: writer,
+            kill: mut transport_kill,
+        } = transport.connect().await?;
+
+        // `cmd_tx` is handed back to the caller (wrapped in `SessionHandle`) so it can drive
+        // further turns; `cmd_rx` moves into the turn loop below. `ready_tx` reports the
+        // session id back once the handshake completes, or is simply dropped on any fatal
+        // early return, which fails the caller's `ready_rx.await` the same way.
+        let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel::<SessionCommand>();
+        let (ready_tx, ready_rx) =
+            tokio::sync::oneshot::channel::<Result<String, ExecutorError>>();
+
         let shared_writer = Arc::new(tokio::sync::Mutex::new(writer));
         let (log_tx, mut log_rx) = mpsc::unbounded_channel::<String>();
 
@@ -258,22 +408,61 @@ impl AcpAgentHarness {
                             let _ = io_fut.await;
                         });
 
-                        // Initialize
-                        let _ = conn
-                            .initialize(proto::InitializeRequest {
+                        // Initialize. A timeout here means the agent never came up at all, so
+                        // it's fatal: tear everything down rather than press on with a
+                        // connection we can't trust.
+                        if AcpTimeouts::bound(
+                            timeouts.initialize_ms,
+                            conn.initialize(proto::InitializeRequest {
                                 protocol_version: proto::V1,
                                 client_capabilities: proto::ClientCapabilities {
+                                    // `fs/read_text_file` and `fs/write_text_file` stay off: they're
+                                    // only safe to advertise once `AcpClient`'s `proto::Client` impl
+                                    // (defined outside this checkout's tracked files) actually
+                                    // handles those requests, calling `resolve_within_sandbox` above
+                                    // for the path canonicalization and sandbox-escape rejection the
+                                    // request asked for. Advertising the capability ahead of that
+                                    // impl would make the agent call a method the client can't serve.
+                                    // Flip this once that handler lands.
                                     fs: proto::FileSystemCapability {
                                         read_text_file: false,
                                         write_text_file: false,
                                         meta: None,
                                     },
+                                    // Unlike `fs` above, this isn't a file-location problem: a
+                                    // real `terminal/create`/`output`/`wait_for_exit`/`kill`/
+                                    // `release`/`resize` backend needs a PTY crate
+                                    // (`portable-pty`, or raw `openpty` via `libc`/`nix`), and
+                                    // there's no `Cargo.toml` in this checkout to declare that
+                                    // dependency in. `transport.rs` hits the same "real impl
+                                    // needs a real dependency" problem for `VsockTransport`/
+                                    // `QuicTransport` and gates them behind the
+                                    // `acp-remote-transport` feature; a PTY-backed terminal
+                                    // implementation should follow that same pattern (its own
+                                    // feature flag) once a manifest exists to add the dependency
+                                    // to. Stays false until then.
                                     terminal: false,
                                     meta: None,
                                 },
                                 meta: None,
-                            })
-                            .await;
+                            }),
+                        )
+                        .await
+                        .is_err()
+                        {
+                            error!("Timed out waiting for ACP initialize");
+                            let _ = log_tx
+                                .send(AcpEvent::Error("ACP initialize timed out".into()).to_string());
+                            if let Some(tx) = exit_signal_tx.take() {
+                                let _ = tx.send(());
+                            }
+                            let _ = transport_kill.kill().await;
+                            drop(conn);
+                            let _ = shutdown_tx.send(true);
+                            let _ = io_handle.await;
+                            drop(log_tx);
+                            return;
+                        }
 
                         // Handle session creation/forking
                         let (acp_session_id, display_session_id, prompt_to_send) =
@@ -286,43 +475,79 @@ impl AcpAgentHarness {
                                 let meta =
                                     history.map(|h| serde_json::json!({ "history_jsonl": h }));
 
-                                match conn
-                                    .new_session(proto::NewSessionRequest {
+                                match AcpTimeouts::bound(
+                                    timeouts.new_session_ms,
+                                    conn.new_session(proto::NewSessionRequest {
                                         mcp_servers: vec![],
                                         cwd: cwd.clone(),
                                         meta,
-                                    })
-                                    .await
+                                    }),
+                                )
+                                .await
                                 {
-                                    Ok(resp) => {
+                                    Ok(Ok(resp)) => {
                                         let resume_prompt = session_manager
                                             .generate_resume_prompt(&new_ui_id, &prompt)
                                             .unwrap_or_else(|_| prompt.clone());
                                         (resp.session_id.0.to_string(), new_ui_id, resume_prompt)
                                     }
-                                    Err(e) => {
+                                    Ok(Err(e)) => {
                                         error!("Failed to create session: {}", e);
                                         return;
                                     }
+                                    Err(_) => {
+                                        error!("Timed out waiting for ACP new_session");
+                                        let _ = log_tx.send(
+                                            AcpEvent::Error("ACP new_session timed out".into())
+                                                .to_string(),
+                                        );
+                                        if let Some(tx) = exit_signal_tx.take() {
+                                            let _ = tx.send(());
+                                        }
+                                        let _ = transport_kill.kill().await;
+                                        drop(conn);
+                                        let _ = shutdown_tx.send(true);
+                                        let _ = io_handle.await;
+                                        drop(log_tx);
+                                        return;
+                                    }
                                 }
                             } else {
                                 // New session
-                                match conn
-                                    .new_session(proto::NewSessionRequest {
+                                match AcpTimeouts::bound(
+                                    timeouts.new_session_ms,
+                                    conn.new_session(proto::NewSessionRequest {
                                         mcp_servers: vec![],
                                         cwd: cwd.clone(),
                                         meta: None,
-                                    })
-                                    .await
+                                    }),
+                                )
+                                .await
                                 {
-                                    Ok(resp) => {
+                                    Ok(Ok(resp)) => {
                                         let sid = resp.session_id.0.to_string();
                                         (sid.clone(), sid, prompt)
                                     }
-                                    Err(e) => {
+                                    Ok(Err(e)) => {
                                         error!("Failed to create session: {}", e);
                                         return;
                                     }
+                                    Err(_) => {
+                                        error!("Timed out waiting for ACP new_session");
+                                        let _ = log_tx.send(
+                                            AcpEvent::Error("ACP new_session timed out".into())
+                                                .to_string(),
+                                        );
+                                        if let Some(tx) = exit_signal_tx.take() {
+                                            let _ = tx.send(());
+                                        }
+                                        let _ = transport_kill.kill().await;
+                                        drop(conn);
+                                        let _ = shutdown_tx.send(true);
+                                        let _ = io_handle.await;
+                                        drop(log_tx);
+                                        return;
+                                    }
                                 }
                             };
 
@@ -362,34 +587,247 @@ impl AcpAgentHarness {
                             meta: None,
                         };
 
-                        // Send the prompt and await completion to obtain stop_reason
-                        match conn.prompt(req).await {
-                            Ok(resp) => {
-                                // Emit done with stop_reason
-                                let stop_reason =
-                                    serde_json::to_string(&resp.stop_reason).unwrap_or_default();
-                                let _ = log_tx.send(AcpEvent::Done(stop_reason).to_string());
+                        // Hand the session id back to the caller before the first turn starts,
+                        // so its `SessionHandle` can already interrupt that turn below.
+                        let handle_delivered =
+                            ready_tx.send(Ok(display_session_id.clone())).is_ok();
+
+                        // Send the first turn and await completion to obtain stop_reason.
+                        // Unlike initialize/new_session, a prompt timeout isn't fatal: the
+                        // agent is alive, just slow, so we cancel the turn, log it and keep the
+                        // connection around for further turns below instead of aborting
+                        // outright. We also race the prompt against `cmd_rx` so the caller's
+                        // `SessionHandle` can interrupt this very first turn instead of having
+                        // to kill the child.
+                        let prompt_fut = AcpTimeouts::bound(timeouts.prompt_ms, conn.prompt(req));
+                        tokio::pin!(prompt_fut);
+                        let mut cmd_channel_closed = false;
+                        loop {
+                            tokio::select! {
+                                res = &mut prompt_fut => {
+                                    match res {
+                                        Ok(Ok(resp)) => {
+                                            let stop_reason =
+                                                serde_json::to_string(&resp.stop_reason).unwrap_or_default();
+                                            let _ = log_tx.send(AcpEvent::Done(stop_reason).to_string());
+                                        }
+                                        Ok(Err(e)) => {
+                                            tracing::debug!("error {} {e} {:?}", e.code, e.data);
+                                            if e.code == agent_client_protocol::ErrorCode::INTERNAL_ERROR.code
+                                                && e.data
+                                                    .as_ref()
+                                                    .is_some_and(|d| d == "server shut down unexpectedly")
+                                            {
+                                                tracing::debug!("ACP server killed");
+                                            } else {
+                                                let _ =
+                                                    log_tx.send(AcpEvent::Error(format!("{e}")).to_string());
+                                            }
+                                        }
+                                        Err(_) => {
+                                            tracing::debug!("ACP prompt timed out, cancelling session");
+                                            let _ = conn
+                                                .cancel(proto::CancelNotification {
+                                                    session_id: proto::SessionId(acp_session_id.clone().into()),
+                                                    meta: None,
+                                                })
+                                                .await;
+                                            let _ = log_tx.send(
+                                                AcpEvent::Error("ACP prompt timed out".into()).to_string(),
+                                            );
+                                        }
+                                    }
+                                    break;
+                                }
+                                maybe_cmd = cmd_rx.recv(), if !cmd_channel_closed => {
+                                    match maybe_cmd {
+                                        Some(SessionCommand::Interrupt { reply }) => {
+                                            let cancel_res = conn
+                                                .cancel(proto::CancelNotification {
+                                                    session_id: proto::SessionId(acp_session_id.clone().into()),
+                                                    meta: None,
+                                                })
+                                                .await
+                                                .map_err(|e| {
+                                                    ExecutorError::Io(std::io::Error::other(format!(
+                                                        "ACP cancel error: {e}"
+                                                    )))
+                                                });
+                                            if cancel_res.is_ok() {
+                                                let _ = log_tx.send(
+                                                    AcpEvent::Error("turn cancelled by interrupt".into())
+                                                        .to_string(),
+                                                );
+                                            }
+                                            let _ = reply.send(cancel_res);
+                                        }
+                                        Some(SessionCommand::SendPrompt { reply, .. }) => {
+                                            let _ = reply.send(Err(session_busy_err()));
+                                        }
+                                        None => {
+                                            cmd_channel_closed = true;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // If nobody took the `SessionHandle` (the caller gave up on
+                        // `bootstrap_acp_connection` entirely), there's nothing left to keep
+                        // this connection alive for.
+                        if !handle_delivered {
+                            if let Some(tx) = exit_signal_tx.take() {
+                                let _ = tx.send(());
                             }
-                            Err(e) => {
-                                tracing::debug!("error {} {e} {:?}", e.code, e.data);
-                                if e.code == agent_client_protocol::ErrorCode::INTERNAL_ERROR.code
-                                    && e.data
-                                        .as_ref()
-                                        .is_some_and(|d| d == "server shut down unexpectedly")
-                                {
-                                    tracing::debug!("ACP server killed");
-                                } else {
-                                    let _ =
-                                        log_tx.send(AcpEvent::Error(format!("{e}")).to_string());
+                            let _ = conn
+                                .cancel(proto::CancelNotification {
+                                    session_id: proto::SessionId(acp_session_id.into()),
+                                    meta: None,
+                                })
+                                .await;
+                            drop(conn);
+                            let _ = shutdown_tx.send(true);
+                            let _ = io_handle.await;
+                            drop(log_tx);
+                            return;
+                        }
+
+                        // Keep the connection alive and drive further turns over it until the
+                        // caller drops every `SessionHandle` clone, which closes `cmd_rx`.
+                        while let Some(cmd) = cmd_rx.recv().await {
+                            match cmd {
+                                SessionCommand::SendPrompt { prompt, reply } => {
+                                    let _ = session_manager.append_raw_line(
+                                        &display_session_id,
+                                        &serde_json::to_string(
+                                            &serde_json::json!({ "user": prompt }),
+                                        )
+                                        .unwrap_or_default(),
+                                    );
+
+                                    let req = proto::PromptRequest {
+                                        session_id: proto::SessionId(
+                                            acp_session_id.clone().into(),
+                                        ),
+                                        prompt: vec![proto::ContentBlock::Text(
+                                            proto::TextContent {
+                                                annotations: None,
+                                                text: prompt,
+                                                meta: None,
+                                            },
+                                        )],
+                                        meta: None,
+                                    };
+
+                                    // Race the turn against further commands so an `interrupt()`
+                                    // sent while this prompt is in flight cancels it right away
+                                    // instead of queueing behind it.
+                                    let turn_fut =
+                                        AcpTimeouts::bound(timeouts.prompt_ms, conn.prompt(req));
+                                    tokio::pin!(turn_fut);
+                                    let mut cmd_channel_closed = false;
+                                    let outcome = loop {
+                                        tokio::select! {
+                                            res = &mut turn_fut => {
+                                                break match res {
+                                                    Ok(Ok(resp)) => {
+                                                        let stop_reason_json =
+                                                            serde_json::to_string(&resp.stop_reason)
+                                                                .unwrap_or_default();
+                                                        let _ = log_tx.send(
+                                                            AcpEvent::Done(stop_reason_json).to_string(),
+                                                        );
+                                                        Ok(resp.stop_reason)
+                                                    }
+                                                    Ok(Err(e)) => {
+                                                        tracing::debug!("error {} {e} {:?}", e.code, e.data);
+                                                        let _ = log_tx
+                                                            .send(AcpEvent::Error(format!("{e}")).to_string());
+                                                        Err(ExecutorError::Io(std::io::Error::other(
+                                                            format!("ACP prompt error: {e}"),
+                                                        )))
+                                                    }
+                                                    Err(_) => {
+                                                        tracing::debug!("ACP prompt timed out");
+                                                        let _ = log_tx.send(
+                                                            AcpEvent::Error("ACP prompt timed out".into())
+                                                                .to_string(),
+                                                        );
+                                                        Err(ExecutorError::Io(std::io::Error::new(
+                                                            std::io::ErrorKind::TimedOut,
+                                                            "ACP prompt timed out",
+                                                        )))
+                                                    }
+                                                };
+                                            }
+                                            maybe_cmd = cmd_rx.recv(), if !cmd_channel_closed => {
+                                                match maybe_cmd {
+                                                    Some(SessionCommand::Interrupt { reply: interrupt_reply }) => {
+                                                        let cancel_res = conn
+                                                            .cancel(proto::CancelNotification {
+                                                                session_id: proto::SessionId(
+                                                                    acp_session_id.clone().into(),
+                                                                ),
+                                                                meta: None,
+                                                            })
+                                                            .await
+                                                            .map_err(|e| {
+                                                                ExecutorError::Io(std::io::Error::other(format!(
+                                                                    "ACP cancel error: {e}"
+                                                                )))
+                                                            });
+                                                        if cancel_res.is_ok() {
+                                                            let _ = log_tx.send(
+                                                                AcpEvent::Error(
+                                                                    "turn cancelled by interrupt".into(),
+                                                                )
+                                                                .to_string(),
+                                                            );
+                                                        }
+                                                        let _ = interrupt_reply.send(cancel_res);
+                                                    }
+                                                    Some(SessionCommand::SendPrompt { reply: queued_reply, .. }) => {
+                                                        let _ = queued_reply.send(Err(session_busy_err()));
+                                                    }
+                                                    None => {
+                                                        cmd_channel_closed = true;
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    };
+                                    let _ = reply.send(outcome);
+                                }
+                                SessionCommand::Interrupt { reply } => {
+                                    let outcome = conn
+                                        .cancel(proto::CancelNotification {
+                                            session_id: proto::SessionId(
+                                                acp_session_id.clone().into(),
+                                            ),
+                                            meta: None,
+                                        })
+                                        .await
+                                        .map_err(|e| {
+                                            ExecutorError::Io(std::io::Error::other(format!(
+                                                "ACP cancel error: {e}"
+                                            )))
+                                        });
+                                    if outcome.is_ok() {
+                                        let _ = log_tx.send(
+                                            AcpEvent::Error("turn cancelled by interrupt".into())
+                                                .to_string(),
+                                        );
+                                    }
+                                    let _ = reply.send(outcome);
                                 }
                             }
                         }
-                        // Notify container of completion
+
+                        // Every `SessionHandle` clone was dropped: tear the connection down.
                         if let Some(tx) = exit_signal_tx.take() {
                             let _ = tx.send(());
                         }
 
-                        // Cancel session work
                         let _ = conn
                             .cancel(proto::CancelNotification {
                                 session_id: proto::SessionId(acp_session_id.into()),
@@ -397,7 +835,6 @@ impl AcpAgentHarness {
                             })
                             .await;
 
-                        // Cleanup
                         drop(conn);
                         let _ = shutdown_tx.send(true);
                         let _ = io_handle.await;
@@ -407,6 +844,7 @@ impl AcpAgentHarness {
             });
         });
 
-        Ok(())
+        let session_id = ready_rx.await.map_err(|_| session_closed_err())??;
+        Ok(SessionHandle { cmd_tx, session_id })
     }
 }
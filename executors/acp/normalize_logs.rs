@@ -21,7 +21,20 @@ use crate::logs::{
     utils::{ConversationPatch, EntryIndexProvider},
 };
 
+/// Normalizes the stdout of an agent harness speaking the default ACP wire format.
 pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
+    normalize_logs_with_parser::<AcpEventParser>(msg_store, worktree_path)
+}
+
+/// Normalizes the stdout of an agent harness using a pluggable `AgentLogParser`, so the
+/// same streaming/patching pipeline can be reused for non-ACP wire formats without
+/// duplicating the stdout loop. `AcpEventParser` (the default ACP implementation) is the
+/// only parser today; future harnesses register their own by implementing the trait.
+pub fn normalize_logs_with_parser<P>(msg_store: Arc<MsgStore>, worktree_path: &Path)
+where
+    P: AgentLogParser + Send + 'static,
+    P::Event: Into<AcpEvent>,
+{
     // stderr normalization
     let entry_index = EntryIndexProvider::start_from(&msg_store);
     normalize_stderr_logs(msg_store.clone(), entry_index.clone());
@@ -31,14 +44,18 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
     // Type aliases to simplify complex state types and appease clippy
     tokio::spawn(async move {
         type ToolStates = std::collections::HashMap<String, PartialToolCallData>;
+        type TurnBatches = std::collections::HashMap<usize, TurnBatch>;
 
         let mut stored_session_id = false;
-        let mut streaming: StreamingState = StreamingState::default();
+        let mut streaming: StreamingBuffers = StreamingBuffers::default();
         let mut tool_states: ToolStates = HashMap::new();
+        let mut turn_batches: TurnBatches = HashMap::new();
+        let mut current_turn: usize = 0;
+        let mut seen_fetch_urls: std::collections::HashSet<String> = std::collections::HashSet::new();
 
         let mut stdout_lines = msg_store.stdout_lines_stream();
         while let Some(Ok(line)) = stdout_lines.next().await {
-            if let Some(parsed) = AcpEventParser::parse_line(&line) {
+            if let Some(parsed) = P::parse_line(&line).map(Into::into) {
                 debug!("Parsed ACP line: {:?}", parsed);
                 match parsed {
                     AcpEvent::SessionStart(id) => {
@@ -58,68 +75,74 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                         msg_store.push_patch(ConversationPatch::add_normalized_entry(idx, entry));
                     }
                     AcpEvent::Done(_) => {
-                        streaming.assistant_text = None;
-                        streaming.thinking_text = None;
+                        flush_streaming_block(&mut streaming, StreamChannel::Message, &msg_store);
+                        flush_streaming_block(&mut streaming, StreamChannel::Thought, &msg_store);
+                        current_turn += 1;
                     }
                     AcpEvent::Message(content) => {
-                        streaming.thinking_text = None;
+                        flush_streaming_block(&mut streaming, StreamChannel::Thought, &msg_store);
                         if let agent_client_protocol::ContentBlock::Text(text) = content {
-                            let is_new = streaming.assistant_text.is_none();
+                            let is_new = !streaming.has_block(StreamChannel::Message);
                             if is_new {
-                                let idx = entry_index.next();
-                                streaming.assistant_text = Some(StreamingText {
-                                    index: idx,
-                                    content: String::new(),
-                                });
+                                current_turn += 1;
                             }
-                            if let Some(ref mut s) = streaming.assistant_text {
-                                s.content.push_str(&text.text);
+                            let idx =
+                                streaming.ensure_block(StreamChannel::Message, || entry_index.next());
+                            streaming.append(StreamChannel::Message, &text.text);
+                            // `ConversationPatch` has no incremental append op, so publishing
+                            // still republishes the whole coalesced entry via `replace`; batch
+                            // deltas behind a byte threshold instead of doing that on every
+                            // single chunk, so a long streamed message doesn't resend its full
+                            // (and growing) text on every token. The threshold flush below and
+                            // the eventual `flush_streaming_block` on finalize together
+                            // guarantee the final text is always published.
+                            if is_new || streaming.should_flush(StreamChannel::Message) {
                                 let entry = NormalizedEntry {
                                     timestamp: None,
                                     entry_type: NormalizedEntryType::AssistantMessage,
-                                    content: s.content.clone(),
+                                    content: streaming.content(StreamChannel::Message).to_string(),
                                     metadata: None,
                                 };
                                 let patch = if is_new {
-                                    ConversationPatch::add_normalized_entry(s.index, entry)
+                                    ConversationPatch::add_normalized_entry(idx, entry)
                                 } else {
-                                    ConversationPatch::replace(s.index, entry)
+                                    ConversationPatch::replace(idx, entry)
                                 };
                                 msg_store.push_patch(patch);
+                                streaming.mark_emitted(StreamChannel::Message);
                             }
                         }
                     }
                     AcpEvent::Thought(content) => {
-                        streaming.assistant_text = None;
+                        flush_streaming_block(&mut streaming, StreamChannel::Message, &msg_store);
                         if let agent_client_protocol::ContentBlock::Text(text) = content {
-                            let is_new = streaming.thinking_text.is_none();
+                            let is_new = !streaming.has_block(StreamChannel::Thought);
                             if is_new {
-                                let idx = entry_index.next();
-                                streaming.thinking_text = Some(StreamingText {
-                                    index: idx,
-                                    content: String::new(),
-                                });
+                                current_turn += 1;
                             }
-                            if let Some(ref mut s) = streaming.thinking_text {
-                                s.content.push_str(&text.text);
+                            let idx =
+                                streaming.ensure_block(StreamChannel::Thought, || entry_index.next());
+                            streaming.append(StreamChannel::Thought, &text.text);
+                            if is_new || streaming.should_flush(StreamChannel::Thought) {
                                 let entry = NormalizedEntry {
                                     timestamp: None,
                                     entry_type: NormalizedEntryType::Thinking,
-                                    content: s.content.clone(),
+                                    content: streaming.content(StreamChannel::Thought).to_string(),
                                     metadata: None,
                                 };
                                 let patch = if is_new {
-                                    ConversationPatch::add_normalized_entry(s.index, entry)
+                                    ConversationPatch::add_normalized_entry(idx, entry)
                                 } else {
-                                    ConversationPatch::replace(s.index, entry)
+                                    ConversationPatch::replace(idx, entry)
                                 };
                                 msg_store.push_patch(patch);
+                                streaming.mark_emitted(StreamChannel::Thought);
                             }
                         }
                     }
                     AcpEvent::Plan(plan) => {
-                        streaming.assistant_text = None;
-                        streaming.thinking_text = None;
+                        flush_streaming_block(&mut streaming, StreamChannel::Message, &msg_store);
+                        flush_streaming_block(&mut streaming, StreamChannel::Thought, &msg_store);
                         let mut body = String::from("Plan:\n");
                         for (i, e) in plan.entries.iter().enumerate() {
                             body.push_str(&format!("{}. {}\n", i + 1, e.content));
@@ -159,21 +182,27 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     }
                     AcpEvent::RequestPermission(perm) => {
                         if let Ok(tc) = agent_client_protocol::ToolCall::try_from(perm.tool_call) {
-                            handle_tool_call(
+                            handle_permission_request::<P>(
                                 &tc,
+                                &perm.options,
+                                current_turn,
                                 &worktree_path,
                                 &mut streaming,
                                 &mut tool_states,
+                                &mut seen_fetch_urls,
                                 &entry_index,
                                 &msg_store,
                             );
                         }
                     }
-                    AcpEvent::ToolCall(tc) => handle_tool_call(
+                    AcpEvent::ToolCall(tc) => handle_tool_call::<P>(
                         &tc,
+                        current_turn,
                         &worktree_path,
                         &mut streaming,
                         &mut tool_states,
+                        &mut turn_batches,
+                        &mut seen_fetch_urls,
                         &entry_index,
                         &msg_store,
                     ),
@@ -187,11 +216,14 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                         }
                         debug!("Got tool call update: {:?}", update);
                         if let Ok(tc) = agent_client_protocol::ToolCall::try_from(update.clone()) {
-                            handle_tool_call(
+                            handle_tool_call::<P>(
                                 &tc,
+                                current_turn,
                                 &worktree_path,
                                 &mut streaming,
                                 &mut tool_states,
+                                &mut turn_batches,
+                                &mut seen_fetch_urls,
                                 &entry_index,
                                 &msg_store,
                             );
@@ -203,25 +235,63 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                 }
             }
         }
+        // The stream can end without a finalizing event (e.g. after an error); publish any
+        // tail the flush threshold is still holding back.
+        flush_streaming_block(&mut streaming, StreamChannel::Message, &msg_store);
+        flush_streaming_block(&mut streaming, StreamChannel::Thought, &msg_store);
 
-        fn handle_tool_call(
+        /// Finalizes a streaming block and, if its last publish didn't already cover every
+        /// byte appended since (the threshold flush in `AcpEvent::Message`/`Thought` batches
+        /// deltas, so it can lag behind `content`), pushes one last `replace` with the full
+        /// text so the entry is never left showing a stale, partially-flushed tail.
+        fn flush_streaming_block(
+            streaming: &mut StreamingBuffers,
+            channel: StreamChannel,
+            msg_store: &Arc<MsgStore>,
+        ) {
+            let Some(block) = streaming.finalize(channel) else {
+                return;
+            };
+            if block.content.len() <= block.emitted_len {
+                return;
+            }
+            let entry_type = match channel {
+                StreamChannel::Message => NormalizedEntryType::AssistantMessage,
+                StreamChannel::Thought => NormalizedEntryType::Thinking,
+            };
+            let entry = NormalizedEntry {
+                timestamp: None,
+                entry_type,
+                content: block.content,
+                metadata: None,
+            };
+            msg_store.push_patch(ConversationPatch::replace(block.index, entry));
+        }
+
+        fn handle_tool_call<P: AgentLogParser>(
             tc: &agent_client_protocol::ToolCall,
+            current_turn: usize,
             worktree_path: &Path,
-            streaming: &mut StreamingState,
+            streaming: &mut StreamingBuffers,
             tool_states: &mut ToolStates,
+            turn_batches: &mut TurnBatches,
+            seen_fetch_urls: &mut std::collections::HashSet<String>,
             entry_index: &EntryIndexProvider,
             msg_store: &Arc<MsgStore>,
         ) {
-            streaming.assistant_text = None;
-            streaming.thinking_text = None;
+            flush_streaming_block(streaming, StreamChannel::Message, msg_store);
+            flush_streaming_block(streaming, StreamChannel::Thought, msg_store);
             let id = tc.id.0.to_string();
             let is_new = !tool_states.contains_key(&id);
-            let tool_data = tool_states.entry(id).or_default();
+            let tool_data = tool_states.entry(id.clone()).or_default();
+            if is_new {
+                tool_data.turn = current_turn;
+            }
             tool_data.extend(tc, worktree_path);
             if is_new {
                 tool_data.index = entry_index.next();
             }
-            let action = map_to_action_type(tool_data);
+            let (action, citation) = map_to_action_type::<P>(tool_data, seen_fetch_urls);
             let entry = NormalizedEntry {
                 timestamp: None,
                 entry_type: NormalizedEntryType::ToolUse {
@@ -229,9 +299,134 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     action_type: action,
                     status: convert_tool_status(&tool_data.status),
                 },
-                content: get_tool_content(tool_data),
+                content: get_tool_content::<P>(tool_data),
+                metadata: entry_metadata(None, citation),
+            };
+            let patch = if is_new {
+                ConversationPatch::add_normalized_entry(tool_data.index, entry)
+            } else {
+                ConversationPatch::replace(tool_data.index, entry)
+            };
+            msg_store.push_patch(patch);
+
+            update_turn_batch(id, tool_states, turn_batches, entry_index, msg_store);
+        }
+
+        /// When two or more tool calls land in the same turn, aggregate them into a single
+        /// "ran N tools in parallel" row alongside their individual entries, so a fan-out of
+        /// concurrent calls doesn't read as interleaved, unrelated lines.
+        fn update_turn_batch(
+            tool_id: String,
+            tool_states: &ToolStates,
+            turn_batches: &mut TurnBatches,
+            entry_index: &EntryIndexProvider,
+            msg_store: &Arc<MsgStore>,
+        ) {
+            let Some(tool_data) = tool_states.get(&tool_id) else {
+                return;
+            };
+            let turn = tool_data.turn;
+            let batch = turn_batches.entry(turn).or_insert_with(|| TurnBatch {
+                index: None,
+                member_ids: Vec::new(),
+            });
+            if !batch.member_ids.contains(&tool_id) {
+                batch.member_ids.push(tool_id);
+            }
+            if batch.member_ids.len() < 2 {
+                return;
+            }
+            // Only now, on the second member, does the batch row actually get rendered, so
+            // this is the first point an entry index is allocated for it.
+            let is_new_batch_entry = batch.index.is_none();
+            let index = *batch.index.get_or_insert_with(|| entry_index.next());
+
+            let members: Vec<&PartialToolCallData> = batch
+                .member_ids
+                .iter()
+                .filter_map(|id| tool_states.get(id))
+                .collect();
+            let any_failed = members
+                .iter()
+                .any(|m| matches!(m.status, agent_client_protocol::ToolCallStatus::Failed));
+            let all_done = members
+                .iter()
+                .all(|m| matches!(m.status, agent_client_protocol::ToolCallStatus::Completed));
+            let status_label = if any_failed {
+                "failed"
+            } else if all_done {
+                "completed"
+            } else {
+                "running"
+            };
+            let mut body = format!(
+                "Ran {} tools in parallel ({}):\n",
+                members.len(),
+                status_label
+            );
+            for m in &members {
+                body.push_str(&format!("- {}\n", m.title));
+            }
+
+            let entry = NormalizedEntry {
+                timestamp: None,
+                entry_type: NormalizedEntryType::SystemMessage,
+                content: body,
                 metadata: None,
             };
+            let patch = if is_new_batch_entry {
+                ConversationPatch::add_normalized_entry(index, entry)
+            } else {
+                ConversationPatch::replace(index, entry)
+            };
+            msg_store.push_patch(patch);
+        }
+
+        fn handle_permission_request<P: AgentLogParser>(
+            tc: &agent_client_protocol::ToolCall,
+            options: &[agent_client_protocol::PermissionOption],
+            current_turn: usize,
+            worktree_path: &Path,
+            streaming: &mut StreamingBuffers,
+            tool_states: &mut ToolStates,
+            seen_fetch_urls: &mut std::collections::HashSet<String>,
+            entry_index: &EntryIndexProvider,
+            msg_store: &Arc<MsgStore>,
+        ) {
+            flush_streaming_block(streaming, StreamChannel::Message, msg_store);
+            flush_streaming_block(streaming, StreamChannel::Thought, msg_store);
+            let id = tc.id.0.to_string();
+            // Establish (or reuse) the tool's entry so the later ToolCall/ToolUpdate for the
+            // same id patches this exact row once the user makes a decision.
+            let is_new = !tool_states.contains_key(&id);
+            let tool_data = tool_states.entry(id).or_default();
+            if is_new {
+                tool_data.turn = current_turn;
+            }
+            tool_data.extend(tc, worktree_path);
+            if is_new {
+                tool_data.index = entry_index.next();
+            }
+            let (action, citation) = map_to_action_type::<P>(tool_data, seen_fetch_urls);
+            // `NormalizedEntryType` has no dedicated permission-request variant, so this still
+            // renders as a `ToolUse` row; the approve/deny options ride along in `metadata`
+            // instead, the existing extension point for side data the typed entry shape has no
+            // field for. `entry_metadata` stamps `is_permission_request: true` unconditionally
+            // (even with zero options) so a frontend can key off that flag directly rather than
+            // inferring the row's kind from whether `permission_options` happens to be non-empty.
+            let options: Vec<PermissionOptionInfo> =
+                options.iter().map(PermissionOptionInfo::from).collect();
+            let metadata = entry_metadata(Some(&options), citation);
+            let entry = NormalizedEntry {
+                timestamp: None,
+                entry_type: NormalizedEntryType::ToolUse {
+                    tool_name: tool_data.title.clone(),
+                    action_type: action,
+                    status: convert_tool_status(&tool_data.status),
+                },
+                content: get_tool_content::<P>(tool_data),
+                metadata,
+            };
             let patch = if is_new {
                 ConversationPatch::add_normalized_entry(tool_data.index, entry)
             } else {
@@ -240,7 +435,13 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
             msg_store.push_patch(patch);
         }
 
-        fn map_to_action_type(tc: &PartialToolCallData) -> ActionType {
+        /// Maps a tool call to its `ActionType`, plus any structured citation payload (search
+        /// hits, a fetched page's final URL/text) the `ActionType` variant itself has no field
+        /// for; callers fold that payload into the entry's `metadata` via `entry_metadata`.
+        fn map_to_action_type<P: AgentLogParser>(
+            tc: &PartialToolCallData,
+            seen_fetch_urls: &mut std::collections::HashSet<String>,
+        ) -> (ActionType, Option<serde_json::Value>) {
             match tc.kind {
                 agent_client_protocol::ToolKind::Read => {
                     // Special-case: read_many_files style titles parsed via helper
@@ -249,35 +450,44 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             r#type: ToolResultValueType::Markdown,
                             value: serde_json::Value::String(text),
                         });
-                        return ActionType::Tool {
-                            tool_name: "read_many_files".to_string(),
-                            arguments: Some(serde_json::Value::String(tc.title.clone())),
-                            result,
-                        };
-                    }
-                    ActionType::FileRead {
-                        path: tc
-                            .path
-                            .clone()
-                            .unwrap_or_default()
-                            .to_string_lossy()
-                            .to_string(),
+                        return (
+                            ActionType::Tool {
+                                tool_name: "read_many_files".to_string(),
+                                arguments: Some(serde_json::Value::String(tc.title.clone())),
+                                result,
+                            },
+                            None,
+                        );
                     }
+                    (
+                        ActionType::FileRead {
+                            path: tc
+                                .path
+                                .clone()
+                                .unwrap_or_default()
+                                .to_string_lossy()
+                                .to_string(),
+                        },
+                        None,
+                    )
                 }
                 agent_client_protocol::ToolKind::Edit => {
                     let changes = extract_file_changes(tc);
-                    ActionType::FileEdit {
-                        path: tc
-                            .path
-                            .clone()
-                            .unwrap_or_default()
-                            .to_string_lossy()
-                            .to_string(),
-                        changes,
-                    }
+                    (
+                        ActionType::FileEdit {
+                            path: tc
+                                .path
+                                .clone()
+                                .unwrap_or_default()
+                                .to_string_lossy()
+                                .to_string(),
+                            changes,
+                        },
+                        None,
+                    )
                 }
                 agent_client_protocol::ToolKind::Execute => {
-                    let command = AcpEventParser::parse_execute_command(&tc.title);
+                    let command = P::parse_execute_command(&tc.title);
                     // Prefer structured raw_output, else fallback to aggregated text content
                     let completed =
                         matches!(tc.status, agent_client_protocol::ToolCallStatus::Completed);
@@ -307,6 +517,19 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                     } else {
                         None
                     };
+                    if result.is_none() {
+                        if let Some(exec) = &tc.exec_output {
+                            let output = exec.tail(200);
+                            if !output.is_empty() || exec.running {
+                                result = Some(crate::logs::CommandRunResult {
+                                    exit_status: exec
+                                        .exit_code
+                                        .map(|code| crate::logs::CommandExitStatus::ExitCode { code }),
+                                    output: Some(output),
+                                });
+                            }
+                        }
+                    }
                     if result.is_none() && completed {
                         result = Some(crate::logs::CommandRunResult {
                             exit_status: Some(crate::logs::CommandExitStatus::Success {
@@ -315,30 +538,38 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             output: None,
                         });
                     }
-                    ActionType::CommandRun { command, result }
+                    (ActionType::CommandRun { command, result }, None)
                 }
-                agent_client_protocol::ToolKind::Delete => ActionType::FileEdit {
-                    path: tc
-                        .path
-                        .clone()
-                        .unwrap_or_default()
-                        .to_string_lossy()
-                        .to_string(),
-                    changes: vec![FileChange::Delete],
-                },
+                agent_client_protocol::ToolKind::Delete => (
+                    ActionType::FileEdit {
+                        path: tc
+                            .path
+                            .clone()
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                            .to_string(),
+                        changes: vec![FileChange::Delete],
+                    },
+                    None,
+                ),
                 agent_client_protocol::ToolKind::Search => {
                     let query = tc
                         .raw_input
                         .as_ref()
+                        .or(tc.raw_input_partial.as_ref())
                         .and_then(|v| serde_json::from_value::<SearchArgs>(v.clone()).ok())
                         .map(|a| a.query)
                         .unwrap_or_else(|| tc.title.clone());
-                    ActionType::Search { query }
+                    // `ActionType::Search` has no field for the result list, so it rides
+                    // along as a `citation` in the entry's `metadata` instead.
+                    let citation = parse_search_results(tc).and_then(|r| serde_json::to_value(r).ok());
+                    (ActionType::Search { query }, citation)
                 }
                 agent_client_protocol::ToolKind::Fetch => {
                     let mut url = tc
                         .raw_input
                         .as_ref()
+                        .or(tc.raw_input_partial.as_ref())
                         .and_then(|v| serde_json::from_value::<FetchArgs>(v.clone()).ok())
                         .map(|a| a.url)
                         .unwrap_or_default();
@@ -348,7 +579,14 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             url = extracted;
                         }
                     }
-                    ActionType::WebFetch { url }
+                    // Only surface the structured citation the first time this URL is
+                    // fetched in the session; later fetches of the same URL keep the plain
+                    // WebFetch entry without repeating the citation. `ActionType::WebFetch`
+                    // has no field for it, so (like Search) it rides in `metadata` instead.
+                    let citation = parse_fetch_result(tc, &url)
+                        .filter(|r| seen_fetch_urls.insert(r.final_url.clone()))
+                        .and_then(|r| serde_json::to_value(r).ok());
+                    (ActionType::WebFetch { url }, citation)
                 }
                 agent_client_protocol::ToolKind::Think => {
                     let tool_name = extract_tool_name_from_id(tc.id.0.as_ref())
@@ -370,15 +608,21 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             value: serde_json::Value::String(text),
                         })
                     };
-                    ActionType::Tool {
-                        tool_name,
-                        arguments,
-                        result,
-                    }
+                    (
+                        ActionType::Tool {
+                            tool_name,
+                            arguments,
+                            result,
+                        },
+                        None,
+                    )
                 }
-                agent_client_protocol::ToolKind::SwitchMode => ActionType::Other {
-                    description: "switch_mode".to_string(),
-                },
+                agent_client_protocol::ToolKind::SwitchMode => (
+                    ActionType::Other {
+                        description: "switch_mode".to_string(),
+                    },
+                    None,
+                ),
                 agent_client_protocol::ToolKind::Other | agent_client_protocol::ToolKind::Move => {
                     // Derive a friendlier tool name from the id if it looks like name-<digits>
                     let tool_name = extract_tool_name_from_id(tc.id.0.as_ref())
@@ -405,15 +649,47 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
                             value: serde_json::Value::String(text),
                         })
                     };
-                    ActionType::Tool {
-                        tool_name,
-                        arguments,
-                        result,
-                    }
+                    (
+                        ActionType::Tool {
+                            tool_name,
+                            arguments,
+                            result,
+                        },
+                        None,
+                    )
                 }
             }
         }
 
+        /// Folds permission options and/or a search/fetch citation into a single `metadata`
+        /// value, or `None` if neither is present. Keeps side data that `NormalizedEntryType`
+        /// and `ActionType` have no dedicated field for out of the entry's typed surface.
+        fn entry_metadata(
+            permission_options: Option<&[PermissionOptionInfo]>,
+            citation: Option<serde_json::Value>,
+        ) -> Option<serde_json::Value> {
+            let options_value = permission_options.and_then(|opts| serde_json::to_value(opts).ok());
+            if options_value.is_none() && citation.is_none() {
+                return None;
+            }
+            let mut map = serde_json::Map::new();
+            if let Some(v) = options_value {
+                // Stamped unconditionally (even when `opts` is empty), so a frontend can
+                // tell a permission-request row apart from a plain `ToolUse` row by this
+                // key's presence alone, rather than inferring it from `permission_options`
+                // happening to be non-empty.
+                map.insert(
+                    "is_permission_request".to_string(),
+                    serde_json::Value::Bool(true),
+                );
+                map.insert("permission_options".to_string(), v);
+            }
+            if let Some(v) = citation {
+                map.insert("citation".to_string(), v);
+            }
+            Some(serde_json::Value::Object(map))
+        }
+
         fn extract_file_changes(tc: &PartialToolCallData) -> Vec<FileChange> {
             let mut changes = Vec::new();
             for c in &tc.content {
@@ -449,11 +725,9 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
             changes
         }
 
-        fn get_tool_content(tc: &PartialToolCallData) -> String {
+        fn get_tool_content<P: AgentLogParser>(tc: &PartialToolCallData) -> String {
             match tc.kind {
-                agent_client_protocol::ToolKind::Execute => {
-                    AcpEventParser::parse_execute_command(&tc.title)
-                }
+                agent_client_protocol::ToolKind::Execute => P::parse_execute_command(&tc.title),
                 agent_client_protocol::ToolKind::Think => "Saving memory".to_string(),
                 agent_client_protocol::ToolKind::Other => {
                     let tool_name = extract_tool_name_from_id(tc.id.0.as_ref())
@@ -515,6 +789,46 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
             if out.is_empty() { None } else { Some(out) }
         }
 
+        /// Parses a completed search tool call's structured `raw_output` into citations a
+        /// downstream viewer can link back to. Unstructured text results aren't parsed into
+        /// individual hits; only a well-formed result array is surfaced.
+        fn parse_search_results(tc: &PartialToolCallData) -> Option<Vec<SearchResultItem>> {
+            let completed = matches!(tc.status, agent_client_protocol::ToolCallStatus::Completed);
+            if !completed {
+                return None;
+            }
+            let raw = tc.raw_output.as_ref()?;
+            serde_json::from_value::<Vec<SearchResultItem>>(raw.clone()).ok()
+        }
+
+        /// Parses a completed fetch tool call's `raw_output` (or its text content as a
+        /// fallback) into a structured result, following any redirect the tool recorded.
+        fn parse_fetch_result(tc: &PartialToolCallData, requested_url: &str) -> Option<FetchResult> {
+            let completed = matches!(tc.status, agent_client_protocol::ToolCallStatus::Completed);
+            if !completed {
+                return None;
+            }
+            if let Some(raw) = tc
+                .raw_output
+                .as_ref()
+                .and_then(|v| serde_json::from_value::<FetchRawOutput>(v.clone()).ok())
+            {
+                return Some(FetchResult {
+                    final_url: raw
+                        .final_url
+                        .or(raw.redirected_url)
+                        .unwrap_or_else(|| requested_url.to_string()),
+                    content_type: raw.content_type,
+                    text: raw.text.unwrap_or_default(),
+                });
+            }
+            collect_text_content(&tc.content).map(|text| FetchResult {
+                final_url: requested_url.to_string(),
+                content_type: None,
+                text,
+            })
+        }
+
         fn convert_tool_status(status: &agent_client_protocol::ToolCallStatus) -> LogToolStatus {
             match status {
                 agent_client_protocol::ToolCallStatus::Pending
@@ -528,6 +842,7 @@ pub fn normalize_logs(msg_store: Arc<MsgStore>, worktree_path: &Path) {
 
 struct PartialToolCallData {
     index: usize,
+    turn: usize,
     id: agent_client_protocol::ToolCallId,
     kind: agent_client_protocol::ToolKind,
     title: String,
@@ -536,6 +851,39 @@ struct PartialToolCallData {
     content: Vec<agent_client_protocol::ToolCallContent>,
     raw_input: Option<serde_json::Value>,
     raw_output: Option<serde_json::Value>,
+    /// Best-effort reconstruction of `raw_input` while its arguments are still streaming in,
+    /// repaired from a truncated JSON fragment via `repair_partial_json`.
+    raw_input_partial: Option<serde_json::Value>,
+    /// Captured stdout/stderr for `ToolKind::Execute` calls, so a long-running command shows
+    /// a growing output log instead of staying blank until it completes.
+    exec_output: Option<ExecOutputStream>,
+}
+
+/// Captured output of a running `ToolKind::Execute` tool call, replaced wholesale on each
+/// update since `ToolCallUpdate.content` always carries the full output so far, not a delta.
+#[derive(Debug, Clone, Default)]
+struct ExecOutputStream {
+    buffer: String,
+    running: bool,
+    exit_code: Option<i32>,
+}
+
+impl ExecOutputStream {
+    /// Returns the last `n` lines captured so far, for rendering a live command log.
+    fn tail(&self, n: usize) -> String {
+        let lines: Vec<&str> = self.buffer.lines().collect();
+        let start = lines.len().saturating_sub(n);
+        lines[start..].join("\n")
+    }
+}
+
+/// Tracks the tool calls emitted within a single turn so concurrent ones can be
+/// rolled up into one aggregate row (see `update_turn_batch`). `index` stays `None` until a
+/// second member arrives, so a turn that only ever runs one tool never reserves (and leaves
+/// unfilled) an entry index for a batch row that's never rendered.
+struct TurnBatch {
+    index: Option<usize>,
+    member_ids: Vec<String>,
 }
 
 impl PartialToolCallData {
@@ -559,14 +907,63 @@ impl PartialToolCallData {
             });
         }
         if !tc.content.is_empty() {
-            self.content = tc.content.clone();
+            if self.kind == agent_client_protocol::ToolKind::Execute {
+                // Like every other `ToolCallUpdate.content`, this is the full output so far,
+                // not just the new chunk (see baseline's unconditional `self.content =
+                // tc.content.clone()`). Replace the exec buffer wholesale instead of
+                // appending, or each update would re-append output we've already captured
+                // and the displayed log would balloon with duplicated text.
+                let mut buffer = String::new();
+                for c in &tc.content {
+                    if let agent_client_protocol::ToolCallContent::Content { content } = c
+                        && let agent_client_protocol::ContentBlock::Text(t) = content
+                    {
+                        buffer.push_str(&t.text);
+                    }
+                }
+                self.exec_output
+                    .get_or_insert_with(ExecOutputStream::default)
+                    .buffer = buffer;
+            } else {
+                self.content = tc.content.clone();
+            }
         }
-        if tc.raw_input.is_some() {
+        // A bare string is a streaming fragment, not a usable `raw_input` object; only the
+        // repaired form below should ever represent it, so `raw_input` itself is left alone
+        // until a real object arrives (see the match below, which also covers this).
+        if matches!(tc.raw_input, Some(ref v) if !v.is_string()) {
             self.raw_input = tc.raw_input.clone();
         }
         if tc.raw_output.is_some() {
             self.raw_output = tc.raw_output.clone();
         }
+        if self.kind == agent_client_protocol::ToolKind::Execute {
+            let exec = self.exec_output.get_or_insert_with(ExecOutputStream::default);
+            if let Some(parsed) = tc
+                .raw_output
+                .as_ref()
+                .and_then(|v| serde_json::from_value::<ShellOutput>(v.clone()).ok())
+            {
+                exec.exit_code = exec.exit_code.or(parsed.exit_code);
+            }
+            exec.running = !matches!(
+                tc.status,
+                agent_client_protocol::ToolCallStatus::Completed
+                    | agent_client_protocol::ToolCallStatus::Failed
+            );
+        }
+        // While arguments are still streaming in, the agent sends them as a raw string
+        // fragment rather than a completed raw_input object; repair and keep the latest
+        // fragment so a partial argument object can still be rendered.
+        match tc.raw_input.as_ref() {
+            Some(serde_json::Value::String(fragment)) => {
+                if let Some(repaired) = repair_partial_json(fragment) {
+                    self.raw_input_partial = Some(repaired);
+                }
+            }
+            Some(value) => self.raw_input_partial = Some(value.clone()),
+            None => {}
+        }
     }
 }
 
@@ -575,6 +972,7 @@ impl Default for PartialToolCallData {
         Self {
             id: agent_client_protocol::ToolCallId(Default::default()),
             index: 0,
+            turn: 0,
             kind: agent_client_protocol::ToolKind::default(),
             title: String::new(),
             status: Default::default(),
@@ -582,10 +980,73 @@ impl Default for PartialToolCallData {
             content: Vec::new(),
             raw_input: None,
             raw_output: None,
+            raw_input_partial: None,
+            exec_output: None,
         }
     }
 }
 
+/// Best-effort repair of a truncated JSON fragment so streaming tool-call arguments can be
+/// rendered before the object closes. Tracks a stack of open `{`/`[`, whether the scan is
+/// inside a string, and escape state (ignoring brackets encountered inside strings); at the
+/// end of the fragment it closes a dangling string, drops a trailing comma or a dangling
+/// object key with no value, then closes the remaining brackets in reverse order.
+fn repair_partial_json(fragment: &str) -> Option<serde_json::Value> {
+    lazy_static! {
+        static ref DANGLING_KEY_RE: Regex =
+            Regex::new(r#""(?:[^"\\]|\\.)*"\s*:\s*$"#).expect("valid regex");
+    }
+
+    let mut stack = Vec::new();
+    let mut in_string = false;
+    let mut escape = false;
+    for ch in fragment.chars() {
+        if in_string {
+            if escape {
+                escape = false;
+            } else if ch == '\\' {
+                escape = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match ch {
+            '"' => in_string = true,
+            '{' | '[' => stack.push(ch),
+            '}' => {
+                if stack.last() == Some(&'{') {
+                    stack.pop();
+                }
+            }
+            ']' => {
+                if stack.last() == Some(&'[') {
+                    stack.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut repaired = fragment.to_string();
+    if in_string {
+        repaired.push('"');
+    }
+    let trimmed = repaired.trim_end().trim_end_matches(',').trim_end();
+    let trimmed = DANGLING_KEY_RE.replace(trimmed, "");
+    repaired = trimmed.trim_end().trim_end_matches(',').to_string();
+
+    for open in stack.iter().rev() {
+        repaired.push(match open {
+            '{' => '}',
+            '[' => ']',
+            _ => unreachable!(),
+        });
+    }
+
+    serde_json::from_str(&repaired).ok()
+}
+
 struct AcpEventParser;
 
 impl AcpEventParser {
@@ -608,6 +1069,36 @@ impl AcpEventParser {
     }
 }
 
+/// A streaming agent wire format that can be normalized by `normalize_logs_with_parser`.
+///
+/// This keeps the core event-to-`NormalizedEntry` machinery (turn tracking, streaming
+/// buffers, tool-call correlation) fixed while letting each agent harness plug in its own
+/// line format — raw JSON-RPC tool-call logs, other function-calling transcripts, etc. —
+/// as long as it can be converted into an `AcpEvent`.
+pub trait AgentLogParser {
+    type Event: Into<AcpEvent>;
+
+    /// Parse a single line of the agent's stdout into an event, if it is one.
+    fn parse_line(line: &str) -> Option<Self::Event>;
+
+    /// Parse the human-readable command out of an execute tool call's title.
+    fn parse_execute_command(title: &str) -> String {
+        title.split(" (").next().unwrap_or(title).trim().to_string()
+    }
+}
+
+impl AgentLogParser for AcpEventParser {
+    type Event = AcpEvent;
+
+    fn parse_line(line: &str) -> Option<AcpEvent> {
+        AcpEventParser::parse_line(line)
+    }
+
+    fn parse_execute_command(title: &str) -> String {
+        AcpEventParser::parse_execute_command(title)
+    }
+}
+
 /// Result of parsing a line
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
@@ -640,6 +1131,176 @@ impl TryFrom<SessionNotification> for AcpEvent {
     }
 }
 
+/// Method name used by the real ACP stdio transport's session-update notification, as
+/// opposed to the pre-split per-line events `AcpEventParser::parse_line` expects.
+const SESSION_UPDATE_METHOD: &str = "session/update";
+
+/// Maximum number of bytes to buffer while looking for the `\r\n\r\n` header terminator
+/// before giving up and resyncing; guards against a malformed stream growing unbounded.
+const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+#[derive(Debug, Clone, Deserialize)]
+struct JsonRpcEnvelope {
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    params: Option<serde_json::Value>,
+}
+
+enum DecodeOutcome {
+    Produced(ParsedLine),
+    Skipped,
+    NeedMore,
+}
+
+/// Decodes the real ACP stdio transport, which is JSON-RPC framed the same way LSP is:
+/// `Content-Length: N\r\n\r\n` headers followed by exactly `N` bytes of body. Feed it byte
+/// slices as they arrive off the child's stdout; it buffers until a full frame is
+/// available and yields `ParsedLine`s, tolerating bodies split across reads, multiple
+/// frames landing in one read, and malformed/overlong headers (by emitting
+/// `ParsedLine::Error` and resyncing).
+///
+/// Not wired into `normalize_logs_with_parser`'s loop above: that loop consumes
+/// `msg_store.stdout_lines_stream()`, which hands back already newline-split `String`s, not
+/// the raw, Content-Length-framed byte stream this decoder expects — by the time a line
+/// reaches that loop, whatever already split it has discarded the framing this decoder
+/// parses. A real wire-protocol consumer would feed this straight from
+/// `AcpTransportIo::reader` (see `transport.rs`) before any line-splitting happens, most
+/// likely from inside `bootstrap_acp_connection` in `harness.rs` rather than here.
+#[derive(Default)]
+pub struct AcpFrameDecoder {
+    buffer: Vec<u8>,
+}
+
+impl AcpFrameDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed newly-read bytes and drain as many complete frames as are now available.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<ParsedLine> {
+        self.buffer.extend_from_slice(bytes);
+        let mut out = Vec::new();
+        loop {
+            match self.try_decode_one() {
+                DecodeOutcome::Produced(line) => out.push(line),
+                DecodeOutcome::Skipped => continue,
+                DecodeOutcome::NeedMore => break,
+            }
+        }
+        out
+    }
+
+    fn try_decode_one(&mut self) -> DecodeOutcome {
+        let Some(header_end) = find_subslice(&self.buffer, b"\r\n\r\n") else {
+            if self.buffer.len() > MAX_HEADER_BYTES {
+                self.buffer.clear();
+                return DecodeOutcome::Produced(ParsedLine::Error(
+                    "ACP frame header exceeded max size without a terminator; resyncing"
+                        .to_string(),
+                ));
+            }
+            return DecodeOutcome::NeedMore;
+        };
+
+        let header_str = String::from_utf8_lossy(&self.buffer[..header_end]).into_owned();
+        let content_length = header_str.split("\r\n").find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim()
+                .eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse::<usize>().ok())
+                .flatten()
+        });
+
+        let body_start = header_end + 4;
+        let Some(len) = content_length else {
+            // No usable Content-Length: drop the bad header and resync on the next frame.
+            self.buffer.drain(..body_start);
+            return DecodeOutcome::Produced(ParsedLine::Error(format!(
+                "ACP frame missing a valid Content-Length header: {header_str:?}"
+            )));
+        };
+
+        if self.buffer.len() < body_start + len {
+            return DecodeOutcome::NeedMore;
+        }
+
+        let body = self.buffer[body_start..body_start + len].to_vec();
+        self.buffer.drain(..body_start + len);
+
+        let envelope = match serde_json::from_slice::<JsonRpcEnvelope>(&body) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                return DecodeOutcome::Produced(ParsedLine::Error(format!(
+                    "invalid ACP JSON-RPC body: {e}"
+                )));
+            }
+        };
+
+        if envelope.method.as_deref() != Some(SESSION_UPDATE_METHOD) {
+            return DecodeOutcome::Skipped;
+        }
+        let Some(params) = envelope.params else {
+            return DecodeOutcome::Skipped;
+        };
+        match serde_json::from_value::<SessionNotification>(params)
+            .ok()
+            .and_then(|n| AcpEvent::try_from(n).ok())
+        {
+            Some(event) => DecodeOutcome::Produced(ParsedLine::Event(event)),
+            None => DecodeOutcome::Skipped,
+        }
+    }
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// One of the allow/reject options offered alongside a permission request, kept
+/// around so the frontend can render an approve/deny UI and report back which
+/// option id the user picked.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PermissionOptionInfo {
+    pub id: String,
+    pub label: String,
+    pub kind: PermissionOptionKindInfo,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub enum PermissionOptionKindInfo {
+    AllowOnce,
+    AllowAlways,
+    RejectOnce,
+    RejectAlways,
+}
+
+impl From<&agent_client_protocol::PermissionOption> for PermissionOptionInfo {
+    fn from(opt: &agent_client_protocol::PermissionOption) -> Self {
+        let kind = match opt.kind {
+            agent_client_protocol::PermissionOptionKind::AllowOnce => {
+                PermissionOptionKindInfo::AllowOnce
+            }
+            agent_client_protocol::PermissionOptionKind::AllowAlways => {
+                PermissionOptionKindInfo::AllowAlways
+            }
+            agent_client_protocol::PermissionOptionKind::RejectOnce => {
+                PermissionOptionKindInfo::RejectOnce
+            }
+            agent_client_protocol::PermissionOptionKind::RejectAlways => {
+                PermissionOptionKindInfo::RejectAlways
+            }
+        };
+        Self {
+            id: opt.id.0.to_string(),
+            label: opt.name.clone(),
+            kind,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct SearchArgs {
     query: String,
@@ -650,6 +1311,36 @@ struct FetchArgs {
     url: String,
 }
 
+/// A single search hit, surfaced as a first-class citation instead of an opaque JSON blob.
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct SearchResultItem {
+    #[serde(default)]
+    pub title: String,
+    pub url: String,
+    #[serde(default)]
+    pub snippet: String,
+}
+
+/// A fetched page, following any redirect the tool recorded.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FetchResult {
+    pub final_url: String,
+    pub content_type: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FetchRawOutput {
+    #[serde(default)]
+    final_url: Option<String>,
+    #[serde(default)]
+    redirected_url: Option<String>,
+    #[serde(default)]
+    content_type: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct ShellOutput {
     #[serde(default)]
@@ -660,14 +1351,107 @@ struct ShellOutput {
     stderr: Option<String>,
 }
 
+/// Which kind of streamed content a block belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamChannel {
+    Message,
+    Thought,
+}
+
+/// Reassembly of streamed assistant/thinking content, one in-progress block per channel. The
+/// wire format (`AgentMessageChunk`/`AgentThoughtChunk`) carries no block index at all, just a
+/// content delta, so there is exactly one active block per channel — plain `Option<StreamingText>`
+/// says that directly instead of routing a single always-present key 0 through a `BTreeMap`.
 #[derive(Debug, Clone, Default)]
-struct StreamingState {
-    assistant_text: Option<StreamingText>,
-    thinking_text: Option<StreamingText>,
+struct StreamingBuffers {
+    message: Option<StreamingText>,
+    thought: Option<StreamingText>,
+}
+
+impl StreamingBuffers {
+    fn slot(&self, channel: StreamChannel) -> &Option<StreamingText> {
+        match channel {
+            StreamChannel::Message => &self.message,
+            StreamChannel::Thought => &self.thought,
+        }
+    }
+
+    fn slot_mut(&mut self, channel: StreamChannel) -> &mut Option<StreamingText> {
+        match channel {
+            StreamChannel::Message => &mut self.message,
+            StreamChannel::Thought => &mut self.thought,
+        }
+    }
+
+    fn has_block(&self, channel: StreamChannel) -> bool {
+        self.slot(channel).is_some()
+    }
+
+    /// Returns the log entry index for the block, allocating one via `alloc` if this is
+    /// the first chunk seen for it.
+    fn ensure_block(&mut self, channel: StreamChannel, alloc: impl FnOnce() -> usize) -> usize {
+        self.slot_mut(channel)
+            .get_or_insert_with(|| StreamingText {
+                index: alloc(),
+                content: String::new(),
+                emitted_len: 0,
+            })
+            .index
+    }
+
+    /// Appends `delta` to the block.
+    fn append(&mut self, channel: StreamChannel, delta: &str) {
+        let block = self
+            .slot_mut(channel)
+            .as_mut()
+            .expect("ensure_block must be called before append");
+        block.content.push_str(delta);
+    }
+
+    /// The block's coalesced content so far, or `""` if nothing has arrived yet.
+    fn content(&self, channel: StreamChannel) -> &str {
+        match self.slot(channel) {
+            Some(block) => &block.content,
+            None => "",
+        }
+    }
+
+    /// Whether enough content has accumulated since the last publish to be worth
+    /// republishing now, instead of waiting for more deltas or the eventual finalize flush.
+    fn should_flush(&self, channel: StreamChannel) -> bool {
+        self.slot(channel)
+            .is_some_and(|block| block.content.len() - block.emitted_len >= STREAM_FLUSH_THRESHOLD_BYTES)
+    }
+
+    /// Marks the block's current content as published, resetting the flush threshold.
+    fn mark_emitted(&mut self, channel: StreamChannel) {
+        if let Some(block) = self.slot_mut(channel).as_mut() {
+            block.emitted_len = block.content.len();
+        }
+    }
+
+    /// Emits the completed block as a single coalesced string, clearing the slot.
+    fn finalize(&mut self, channel: StreamChannel) -> Option<StreamingText> {
+        self.slot_mut(channel).take()
+    }
 }
 
+/// How many new bytes must accumulate in a streaming block before it's republished early.
+///
+/// There's no `ConversationPatch::append_text(index, delta)` to reach for here: `replace`
+/// and `add_normalized_entry` are JSON Patch (RFC 6902) `replace`/`add` ops under the hood,
+/// and RFC 6902 has no "append to the string at this pointer" op, only "set the value at
+/// this pointer" — an incremental-append variant would need a non-standard op the consuming
+/// frontend doesn't know how to apply, so it can't be added without changing that wire
+/// contract too (and `ConversationPatch`'s defining module isn't in this checkout to make
+/// that change in anyway). Every publish therefore resends the whole coalesced string;
+/// batching deltas up to this size bounds how often that O(current length) republish fires
+/// instead of firing on every single small chunk.
+const STREAM_FLUSH_THRESHOLD_BYTES: usize = 256;
+
 #[derive(Debug, Clone)]
 struct StreamingText {
     index: usize,
     content: String,
+    emitted_len: usize,
 }
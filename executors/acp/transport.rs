@@ -0,0 +1,239 @@
+use std::{future::Future, net::SocketAddr, pin::Pin, sync::Arc};
+
+use command_group::AsyncGroupChild;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use crate::executors::ExecutorError;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The raw byte streams an ACP JSON-RPC connection is framed on top of, plus a side channel
+/// for anything the harness wants to log out-of-band and a handle to tear the channel down.
+/// `bootstrap_acp_connection` only ever touches this struct, never the concrete transport, so
+/// swapping a local child process for a vsock or QUIC link doesn't change any of the
+/// session-forking, event-forwarding, or persistence logic layered on top.
+pub struct AcpTransportIo {
+    pub reader: Box<dyn AsyncRead + Send + Unpin>,
+    pub writer: Box<dyn AsyncWrite + Send + Unpin>,
+    /// Where harness-generated log lines (session start, errors, done) should be written.
+    /// For a local child this is a duplicate of the process's own stdout; remote transports
+    /// have no child stdout to dup, so they hand back this process's stdout instead.
+    pub log_writer: Box<dyn AsyncWrite + Send + Unpin>,
+    pub kill: Box<dyn AcpTransportKill>,
+}
+
+/// Tears down the channel a transport opened. For a local child process this is a no-op
+/// (the process is spawned with `kill_on_drop(true)`, so dropping `SpawnedChild` already
+/// reclaims it); for a vsock or QUIC transport there's no OS-level child to rely on, so `kill`
+/// is what actually closes the connection on a fatal timeout.
+pub trait AcpTransportKill: Send {
+    fn kill(&mut self) -> BoxFuture<'_, std::io::Result<()>>;
+}
+
+/// How the ACP agent is reached: a local child process on this host today, or (via the impls
+/// below) a vsock connection into a microVM or a QUIC stream to a remote host.
+pub trait AcpTransport: Send {
+    fn connect(&mut self) -> BoxFuture<'_, Result<AcpTransportIo, ExecutorError>>;
+}
+
+struct NoopKill;
+
+impl AcpTransportKill for NoopKill {
+    fn kill(&mut self) -> BoxFuture<'_, std::io::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Default transport: the agent is a child process spawned on this host, wired over its own
+/// stdin/stdout. This is the only transport in use today (Gemini, Qwen); it just gives the
+/// existing spawn path a name so it can sit behind `AcpTransport`.
+pub struct LocalProcessTransport<'a> {
+    child: &'a mut AsyncGroupChild,
+}
+
+impl<'a> LocalProcessTransport<'a> {
+    pub fn new(child: &'a mut AsyncGroupChild) -> Self {
+        Self { child }
+    }
+}
+
+impl<'a> AcpTransport for LocalProcessTransport<'a> {
+    fn connect(&mut self) -> BoxFuture<'_, Result<AcpTransportIo, ExecutorError>> {
+        Box::pin(async move {
+            let orig_stdout = self.child.inner().stdout.take().ok_or_else(|| {
+                ExecutorError::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "Child process has no stdout",
+                ))
+            })?;
+            let orig_stdin = self.child.inner().stdin.take().ok_or_else(|| {
+                ExecutorError::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "Child process has no stdin",
+                ))
+            })?;
+            let log_writer = crate::stdout_dup::create_stdout_pipe_writer(self.child)?;
+
+            Ok(AcpTransportIo {
+                reader: Box::new(orig_stdout),
+                writer: Box::new(orig_stdin),
+                log_writer: Box::new(log_writer),
+                kill: Box::new(NoopKill),
+            })
+        })
+    }
+}
+
+/// Address of an ACP agent listening on a vsock port inside a microVM, reached the way
+/// `p9cpu` reaches its guest agent: by (CID, port) rather than a host/port pair.
+///
+/// Gated behind `acp-remote-transport`: nothing constructs this yet (only
+/// `LocalProcessTransport` is wired into `bootstrap_acp_connection`), and it pulls in
+/// `tokio_vsock` as a real dependency. Build it out once a vsock-backed caller exists.
+#[cfg(feature = "acp-remote-transport")]
+#[derive(Debug, Clone, Copy)]
+pub struct VsockAddr {
+    pub cid: u32,
+    pub port: u32,
+}
+
+/// Connects to an ACP agent running inside an isolated microVM over vsock, so the agent's
+/// shell commands and file access never touch the host directly.
+#[cfg(feature = "acp-remote-transport")]
+pub struct VsockTransport {
+    addr: VsockAddr,
+}
+
+#[cfg(feature = "acp-remote-transport")]
+impl VsockTransport {
+    pub fn new(addr: VsockAddr) -> Self {
+        Self { addr }
+    }
+}
+
+#[cfg(feature = "acp-remote-transport")]
+struct VsockKill {
+    stream: Option<tokio_vsock::VsockStream>,
+}
+
+#[cfg(feature = "acp-remote-transport")]
+impl AcpTransportKill for VsockKill {
+    fn kill(&mut self) -> BoxFuture<'_, std::io::Result<()>> {
+        Box::pin(async move {
+            // Dropping this cloned handle alone would leave the socket open through the
+            // reader/writer halves, so shut the connection itself down.
+            if let Some(stream) = self.stream.take() {
+                stream.shutdown(std::net::Shutdown::Both)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "acp-remote-transport")]
+impl AcpTransport for VsockTransport {
+    fn connect(&mut self) -> BoxFuture<'_, Result<AcpTransportIo, ExecutorError>> {
+        let addr = self.addr;
+        Box::pin(async move {
+            let stream = tokio_vsock::VsockStream::connect(tokio_vsock::VsockAddr::new(
+                addr.cid, addr.port,
+            ))
+            .await
+            .map_err(ExecutorError::Io)?;
+            let kill_stream = stream.try_clone().map_err(ExecutorError::Io)?;
+            let (reader, writer) = tokio::io::split(stream);
+
+            Ok(AcpTransportIo {
+                reader: Box::new(reader),
+                writer: Box::new(writer),
+                log_writer: Box::new(tokio::io::stdout()),
+                kill: Box::new(VsockKill {
+                    stream: Some(kill_stream),
+                }),
+            })
+        })
+    }
+}
+
+/// Config for reaching a remote ACP agent over a QUIC stream, e.g. a Gemini/Qwen sandbox
+/// running on a separate host (the `quinoa`-style remote execution model).
+///
+/// Gated behind `acp-remote-transport`: nothing constructs this yet, and it pulls in `quinn`
+/// as a real dependency. Build it out once a QUIC-backed caller exists.
+#[cfg(feature = "acp-remote-transport")]
+#[derive(Debug, Clone)]
+pub struct QuicTransportConfig {
+    pub server_addr: SocketAddr,
+    pub server_name: String,
+    /// ALPN protocol identifier for the server, e.g. `b"acp".to_vec()`. `connect` does not
+    /// apply it: the client config is quinn's platform-verifier default, which offers no ALPN.
+    pub alpn: Vec<u8>,
+}
+
+/// Connects to a remote ACP agent over a QUIC stream.
+#[cfg(feature = "acp-remote-transport")]
+pub struct QuicTransport {
+    config: QuicTransportConfig,
+}
+
+#[cfg(feature = "acp-remote-transport")]
+impl QuicTransport {
+    pub fn new(config: QuicTransportConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[cfg(feature = "acp-remote-transport")]
+struct QuicKill {
+    connection: quinn::Connection,
+}
+
+#[cfg(feature = "acp-remote-transport")]
+impl AcpTransportKill for QuicKill {
+    fn kill(&mut self) -> BoxFuture<'_, std::io::Result<()>> {
+        Box::pin(async move {
+            self.connection.close(0u32.into(), b"acp timeout");
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "acp-remote-transport")]
+impl AcpTransport for QuicTransport {
+    fn connect(&mut self) -> BoxFuture<'_, Result<AcpTransportIo, ExecutorError>> {
+        let config = self.config.clone();
+        Box::pin(async move {
+            let mut client_config = quinn::ClientConfig::with_platform_verifier();
+            let mut transport_config = quinn::TransportConfig::default();
+            transport_config.max_idle_timeout(None);
+            client_config.transport_config(Arc::new(transport_config));
+
+            let mut endpoint =
+                quinn::Endpoint::client("0.0.0.0:0".parse().expect("valid local bind addr"))
+                    .map_err(ExecutorError::Io)?;
+            endpoint.set_default_client_config(client_config);
+
+            let connection = endpoint
+                .connect(config.server_addr, &config.server_name)
+                .map_err(|e| {
+                    ExecutorError::Io(std::io::Error::other(format!("quic connect: {e}")))
+                })?
+                .await
+                .map_err(|e| {
+                    ExecutorError::Io(std::io::Error::other(format!("quic handshake: {e}")))
+                })?;
+
+            let (writer, reader) = connection
+                .open_bi()
+                .await
+                .map_err(|e| ExecutorError::Io(std::io::Error::other(format!("quic open_bi: {e}"))))?;
+
+            Ok(AcpTransportIo {
+                reader: Box::new(reader),
+                writer: Box::new(writer),
+                log_writer: Box::new(tokio::io::stdout()),
+                kill: Box::new(QuicKill { connection }),
+            })
+        })
+    }
+}